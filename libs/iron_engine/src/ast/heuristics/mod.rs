@@ -1,5 +1,5 @@
 pub mod sanitizer;
 pub mod table;
 
-pub use sanitizer::NumericSanitizer;
+pub use sanitizer::{NumericSanitizer, SanitizerConfig};
 pub use table::{BoundingBox, ColumnBoundaryDetector, RowCohesionMapper, TextElement};
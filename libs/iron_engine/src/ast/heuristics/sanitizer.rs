@@ -1,5 +1,22 @@
 use regex::Regex;
 
+/// Currency markers stripped by the VND normalization pass (longest first).
+/// Unaccented spellings must be listed too: the OCR fix-up (`o` → `0`) would
+/// otherwise turn "dong" into an extra digit.
+const VND_MARKERS: [&str; 12] = [
+    "Đồng", "đồng", "DONG", "Dong", "dong", "VNĐ", "VND", "vnđ", "vnd", "₫", "Đ", "đ",
+];
+
+/// Optional passes applied by `NumericSanitizer::sanitize_with`.
+/// The default configuration reproduces `NumericSanitizer::sanitize` exactly.
+#[derive(Debug, Clone, Default)]
+pub struct SanitizerConfig {
+    /// Normalize Vietnamese currency formatting before parsing:
+    /// NBSP-family thousand separators become plain spaces and a leading or
+    /// trailing `₫`/`đ`/`VNĐ`/`dong` marker is detached (see `NumericSanitizer::normalize_vnd`).
+    pub normalize_vnd: bool,
+}
+
 /// The NumericSanitizer is responsible for cleaning up messy OCR numbers
 /// commonly found in Vietnamese construction documents, converting them reliably into `f64`.
 pub struct NumericSanitizer;
@@ -9,7 +26,16 @@ impl NumericSanitizer {
     /// Handles Vietnamese number formats (e.g. `1.250.000,50` or `1 250 000.50`),
     /// fixes common OCR mistakes (like 'l' to '1', 'o' to '0'), and strips wrappers like `()`.
     pub fn sanitize(raw: &str) -> Option<f64> {
-        let mut text = raw.trim().to_string();
+        Self::sanitize_with(raw, &SanitizerConfig::default())
+    }
+
+    /// Same as `sanitize`, with the optional passes enabled by `config`.
+    pub fn sanitize_with(raw: &str, config: &SanitizerConfig) -> Option<f64> {
+        let mut text = if config.normalize_vnd {
+            Self::normalize_vnd(raw)
+        } else {
+            raw.trim().to_string()
+        };
         if text.is_empty() {
             return None;
         }
@@ -26,9 +52,9 @@ impl NumericSanitizer {
             .replace(['O', 'o'], "0")
             .replace('S', "5");
 
-        // 3. Keep only digits, periods, commas, and spaces
+        // 3. Keep only digits, periods, commas, and spaces (trim the gap a stripped "₫" leaves)
         let re = Regex::new(r"[^\d.,\s]").unwrap();
-        text = re.replace_all(&text, "").to_string();
+        text = re.replace_all(&text, "").trim().to_string();
 
         // 4. Handle thousands separators vs decimal points
         // In VN, 1.000.000,50 is common. In US, 1,000,000.50 is common.
@@ -83,6 +109,55 @@ impl NumericSanitizer {
             None
         }
     }
+
+    /// Normalizes a VND amount string without changing its numeric groups.
+    ///
+    /// `1\u{a0}250\u{a0}000₫` → `1 250 000 ₫`. Non-breaking and narrow spaces
+    /// become ASCII spaces (so they are treated as thousand separators rather
+    /// than garbage), whitespace runs collapse to one space, and any leading or
+    /// trailing currency marker is replaced by a single ` ₫` suffix.
+    ///
+    /// Accounting negatives are unwrapped before marker detection so a marker
+    /// inside the parentheses is still found; the sign is re-applied as a
+    /// leading `-`: `(1.000.000 dong)` and `₫(1.000.000)` → `-1.000.000 ₫`.
+    pub fn normalize_vnd(raw: &str) -> String {
+        let spaced: String = raw
+            .chars()
+            .map(|c| match c {
+                '\u{00A0}' | '\u{202F}' | '\u{2007}' => ' ',
+                _ => c,
+            })
+            .collect();
+
+        let mut body = spaced.trim();
+        let mut has_marker = false;
+        let mut is_negative = false;
+        loop {
+            if let Some(rest) = VND_MARKERS.iter().find_map(|m| body.strip_suffix(m)) {
+                body = rest.trim_end();
+                has_marker = true;
+            } else if let Some(rest) = VND_MARKERS.iter().find_map(|m| body.strip_prefix(m)) {
+                body = rest.trim_start();
+                has_marker = true;
+            } else if let Some(inner) = body.strip_prefix('(').and_then(|b| b.strip_suffix(')')) {
+                body = inner.trim();
+                is_negative = true;
+            } else if let Some(rest) = body.strip_prefix('-') {
+                body = rest.trim_start();
+                is_negative = true;
+            } else {
+                break;
+            }
+        }
+
+        let sign = if is_negative { "-" } else { "" };
+        let body = body.split_whitespace().collect::<Vec<_>>().join(" ");
+        if has_marker {
+            format!("{}{} ₫", sign, body)
+        } else {
+            format!("{}{}", sign, body)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -95,6 +170,8 @@ mod tests {
         assert_eq!(NumericSanitizer::sanitize("1.250.000,50"), Some(1250000.5));
         assert_eq!(NumericSanitizer::sanitize("1.000,5"), Some(1000.5));
         assert_eq!(NumericSanitizer::sanitize("500,00"), Some(500.0));
+        // A stripped currency suffix must not turn the decimal comma into a thousands group
+        assert_eq!(NumericSanitizer::sanitize("1000,50 đ"), Some(1000.5));
     }
 
     #[test]
@@ -139,4 +216,91 @@ mod tests {
         assert_eq!(NumericSanitizer::sanitize(""), None);
         assert_eq!(NumericSanitizer::sanitize("   "), None);
     }
+
+    #[test]
+    fn test_normalize_vnd_spacing() {
+        assert_eq!(
+            NumericSanitizer::normalize_vnd("1\u{a0}250\u{a0}000₫"),
+            "1 250 000 ₫"
+        );
+        assert_eq!(
+            NumericSanitizer::normalize_vnd("  1.000.000   VNĐ "),
+            "1.000.000 ₫"
+        );
+        assert_eq!(NumericSanitizer::normalize_vnd("2.500,50"), "2.500,50");
+        assert_eq!(
+            NumericSanitizer::normalize_vnd("₫ 1.000.000"),
+            "1.000.000 ₫"
+        );
+        assert_eq!(
+            NumericSanitizer::normalize_vnd("1.000.000 dong"),
+            "1.000.000 ₫"
+        );
+    }
+
+    #[test]
+    fn test_numeric_sanitizer_vnd_opt_in() {
+        let config = SanitizerConfig {
+            normalize_vnd: true,
+        };
+
+        // NBSP thousand separators only parse with the VND pass enabled
+        assert_eq!(
+            NumericSanitizer::sanitize("1\u{a0}000\u{a0}000\u{a0}₫"),
+            None
+        );
+        assert_eq!(
+            NumericSanitizer::sanitize_with("1\u{a0}000\u{a0}000\u{a0}₫", &config),
+            Some(1000000.0)
+        );
+        assert_eq!(
+            NumericSanitizer::sanitize_with("1\u{202f}250\u{202f}000,50 đ", &config),
+            Some(1250000.5)
+        );
+        assert_eq!(
+            NumericSanitizer::sanitize_with("1.000.000 ₫", &config),
+            Some(1000000.0)
+        );
+        assert_eq!(
+            NumericSanitizer::sanitize_with("(2.500.000 VNĐ)", &config),
+            Some(-2500000.0)
+        );
+
+        // Unaccented markers are stripped before the OCR fix-up can read "o" as "0"
+        for raw in ["1.000.000 dong", "1.000.000 Dong", "1.000.000 DONG"] {
+            assert_eq!(
+                NumericSanitizer::sanitize_with(raw, &config),
+                Some(1000000.0),
+                "{raw}"
+            );
+        }
+
+        // Leading marker
+        assert_eq!(
+            NumericSanitizer::sanitize_with("₫ 1.000.000", &config),
+            Some(1000000.0)
+        );
+        assert_eq!(
+            NumericSanitizer::sanitize_with("₫1\u{a0}500\u{a0}000", &config),
+            Some(1500000.0)
+        );
+
+        // Markers inside or outside an accounting-negative wrapper keep the sign
+        for raw in [
+            "(1.000.000 dong)",
+            "(1.000.000 ₫)",
+            "₫(1.000.000)",
+            "-1.000.000 đồng",
+        ] {
+            assert_eq!(
+                NumericSanitizer::sanitize_with(raw, &config),
+                Some(-1000000.0),
+                "{raw}"
+            );
+        }
+        assert_eq!(
+            NumericSanitizer::normalize_vnd("(1.000.000 dong)"),
+            "-1.000.000 ₫"
+        );
+    }
 }
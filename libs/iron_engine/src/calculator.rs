@@ -14,6 +14,29 @@ use iron_table::{
 };
 use std::collections::HashMap;
 
+/// Deviation (%) at or above which a project is `Warning`.
+const WARNING_THRESHOLD_PCT: f64 = 5.0;
+/// Deviation (%) at or above which a project is `Critical`.
+const CRITICAL_THRESHOLD_PCT: f64 = 15.0;
+
+/// Auditable result of status determination.
+///
+/// `triggered_rules` lists exactly which threshold conditions fired for the
+/// given input, so a disputed status can be traced back to its cause.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusExplanation {
+    pub status: ProjectStatus,
+    pub triggered_rules: Vec<String>,
+    pub deviation_percentage: f64,
+}
+
+impl StatusExplanation {
+    /// Human-readable reason for display (`ProjectTruth::status_reason`).
+    pub fn reason(&self) -> String {
+        self.triggered_rules.join("; ")
+    }
+}
+
 /// Derive ProjectTruth from a set of validated TableTruths.
 ///
 /// **Rules:**
@@ -105,15 +128,16 @@ pub fn derive_project_truth(tables: &[TableTruth], timestamp: String) -> Result<
         absolute,
     };
 
-    let status = determine_status(&deviation);
+    let explanation = determine_status(&deviation);
 
     let mut project_truth = ProjectTruth {
         project_name: "Consolidated Dashboard".to_string(),
         last_updated: timestamp,
         data_source: "iron_engine".to_string(),
 
-        project_status: status,
-        status_reason: "Deterministic calculation".to_string(),
+        project_status: explanation.status.clone(),
+        status_reason: explanation.reason(),
+        status_rules: explanation.triggered_rules,
 
         financials,
         deviation,
@@ -140,13 +164,36 @@ pub fn derive_project_truth(tables: &[TableTruth], timestamp: String) -> Result<
     Ok(project_truth)
 }
 
-fn determine_status(deviation: &DeviationSummary) -> ProjectStatus {
-    if deviation.percentage < 5.0 {
+fn determine_status(deviation: &DeviationSummary) -> StatusExplanation {
+    let pct = deviation.percentage;
+    let mut triggered_rules = Vec::new();
+
+    let status = if pct < WARNING_THRESHOLD_PCT {
+        triggered_rules.push(format!(
+            "deviation {:.2}% < {}% warning threshold",
+            pct, WARNING_THRESHOLD_PCT
+        ));
         ProjectStatus::Safe
-    } else if deviation.percentage < 15.0 {
-        ProjectStatus::Warning
     } else {
-        ProjectStatus::Critical
+        triggered_rules.push(format!(
+            "deviation {:.2}% >= {}% warning threshold",
+            pct, WARNING_THRESHOLD_PCT
+        ));
+        if pct < CRITICAL_THRESHOLD_PCT {
+            ProjectStatus::Warning
+        } else {
+            triggered_rules.push(format!(
+                "deviation {:.2}% >= {}% critical threshold",
+                pct, CRITICAL_THRESHOLD_PCT
+            ));
+            ProjectStatus::Critical
+        }
+    };
+
+    StatusExplanation {
+        status,
+        triggered_rules,
+        deviation_percentage: pct,
     }
 }
 
@@ -158,35 +205,112 @@ mod tests {
 
     #[test]
     fn test_calculator_pure_rust_logic() {
-        let table = TableTruth {
+        let table = two_column_table(["total_cost", "total_paid"], [100.0, 40.0]);
+
+        let truth = derive_project_truth(&[table], "now".into()).unwrap();
+        assert_eq!(truth.financials.total_cost, 100.0);
+        assert_eq!(truth.financials.total_paid, 40.0);
+        assert_eq!(truth.financials.remaining, 60.0);
+    }
+
+    #[test]
+    fn test_status_explanation_lists_triggered_rules() {
+        let critical = determine_status(&DeviationSummary {
+            percentage: 16.0,
+            absolute: 160.0,
+        });
+        assert_eq!(critical.status, ProjectStatus::Critical);
+        assert_eq!(
+            critical.triggered_rules,
+            vec![
+                "deviation 16.00% >= 5% warning threshold".to_string(),
+                "deviation 16.00% >= 15% critical threshold".to_string(),
+            ]
+        );
+
+        let warning = determine_status(&DeviationSummary {
+            percentage: 5.0,
+            absolute: 50.0,
+        });
+        assert_eq!(warning.status, ProjectStatus::Warning);
+        assert_eq!(
+            warning.triggered_rules,
+            vec!["deviation 5.00% >= 5% warning threshold".to_string()]
+        );
+
+        let safe = determine_status(&DeviationSummary {
+            percentage: -2.0,
+            absolute: -20.0,
+        });
+        assert_eq!(safe.status, ProjectStatus::Safe);
+        assert_eq!(safe.reason(), "deviation -2.00% < 5% warning threshold");
+    }
+
+    #[test]
+    fn test_project_truth_carries_status_rules() {
+        // actual 120 vs budget 100 -> 20% deviation -> Critical
+        let table = two_column_table(["budget", "actual"], [100.0, 120.0]);
+
+        let truth = derive_project_truth(&[table], "now".into()).unwrap();
+        assert_eq!(truth.project_status, ProjectStatus::Critical);
+        assert_eq!(
+            truth.status_rules,
+            vec![
+                "deviation 20.00% >= 5% warning threshold".to_string(),
+                "deviation 20.00% >= 15% critical threshold".to_string(),
+            ]
+        );
+        assert_eq!(
+            truth.status_reason,
+            "deviation 20.00% >= 5% warning threshold; deviation 20.00% >= 15% critical threshold"
+        );
+    }
+
+    /// One-row table with two critical Float64 columns.
+    fn two_column_table(names: [&str; 2], values: [f64; 2]) -> TableTruth {
+        TableTruth {
             table_id: "test".to_string(),
             source_file: PathBuf::from("test.pdf"),
             source_page: 1,
             schema: TableSchema {
-                columns: vec![
-                    ColumnDef { name: "total_cost".to_string(), dtype: DataType::Float64, unit: None, nullable: false, is_critical: true },
-                    ColumnDef { name: "total_paid".to_string(), dtype: DataType::Float64, unit: None, nullable: false, is_critical: true },
-                ],
+                columns: names
+                    .iter()
+                    .map(|name| ColumnDef {
+                        name: name.to_string(),
+                        dtype: DataType::Float64,
+                        unit: None,
+                        nullable: false,
+                        is_critical: true,
+                    })
+                    .collect(),
                 row_count: 1,
                 col_count: 2,
             },
-            rows: vec![
-                TableRow {
-                    row_idx: 0,
-                    cells: vec![
-                        TableCell { global_id: "1".into(), row_idx: 0, col_idx: 0, value: CellValue::Float(100.0), bbox: dummy_bbox(), confidence: 1.0, source_text: "".into(), encoding_status: Default::default(), encoding_evidence: None },
-                        TableCell { global_id: "2".into(), row_idx: 0, col_idx: 1, value: CellValue::Float(40.0), bbox: dummy_bbox(), confidence: 1.0, source_text: "".into(), encoding_status: Default::default(), encoding_evidence: None },
-                    ]
-                }
-            ],
-            extraction_meta: iron_table::ExtractionMeta { tool_version: "".into(), timestamp: "".into(), confidence_score: 1.0 },
+            rows: vec![TableRow {
+                row_idx: 0,
+                cells: values
+                    .iter()
+                    .enumerate()
+                    .map(|(col_idx, &val)| TableCell {
+                        global_id: format!("0_{}", col_idx),
+                        row_idx: 0,
+                        col_idx,
+                        value: CellValue::Float(val),
+                        bbox: dummy_bbox(),
+                        confidence: 1.0,
+                        source_text: "".into(),
+                        encoding_status: Default::default(),
+                        encoding_evidence: None,
+                    })
+                    .collect(),
+            }],
+            extraction_meta: iron_table::ExtractionMeta {
+                tool_version: "".into(),
+                timestamp: "".into(),
+                confidence_score: 1.0,
+            },
             bbox: dummy_bbox(),
-        };
-
-        let truth = derive_project_truth(&[table], "now".into()).unwrap();
-        assert_eq!(truth.financials.total_cost, 100.0);
-        assert_eq!(truth.financials.total_paid, 40.0);
-        assert_eq!(truth.financials.remaining, 60.0);
+        }
     }
 
     fn dummy_bbox() -> BoundingBox {
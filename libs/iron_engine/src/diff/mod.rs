@@ -14,7 +14,7 @@ pub mod section_diff;
 pub mod table_diff;
 
 pub use heading_diff::{HeadingDiffer, HeadingEntry};
-pub use report::{Delta, DeltaType, DiffReport};
+pub use report::{Delta, DeltaType, DiffReport, DiffSummary};
 pub use section_diff::SectionDiffer;
 pub use table_diff::TableDiffer;
 
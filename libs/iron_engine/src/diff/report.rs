@@ -34,7 +34,36 @@ pub struct DiffReport {
     pub deltas: Vec<Delta>,
 }
 
+/// Per-kind delta counts for a `DiffReport`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DiffSummary {
+    pub added: usize,
+    pub removed: usize,
+    pub value_mismatches: usize,
+    pub structural_changes: usize,
+}
+
 impl DiffReport {
     pub fn new(deltas: Vec<Delta>) -> Self {
         Self { deltas }
-    }}
+    }
+
+    /// True when no deltas were detected.
+    pub fn is_identical(&self) -> bool {
+        self.deltas.is_empty()
+    }
+
+    /// Counts deltas by kind.
+    pub fn summary(&self) -> DiffSummary {
+        let mut summary = DiffSummary::default();
+        for delta in &self.deltas {
+            match delta.delta_type {
+                DeltaType::Added => summary.added += 1,
+                DeltaType::Removed => summary.removed += 1,
+                DeltaType::ValueMismatch { .. } => summary.value_mismatches += 1,
+                DeltaType::StructuralChange { .. } => summary.structural_changes += 1,
+            }
+        }
+        summary
+    }
+}
@@ -5,42 +5,92 @@
 
 use crate::ast::node::{Node, NumericIndexEntry, RowType, Section};
 
-/// Export a collection of AST sections to clean Markdown.
+/// Heading syntax used by the Markdown exporter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub enum HeadingStyle {
+    /// `# Title`, `## Title`, ...
+    #[default]
+    Atx,
+    /// `Title` underlined with `===` (H1) or `---` (H2).
+    /// Setext has no H3+, so deeper levels fall back to ATX.
+    Setext,
+}
+
+/// Markdown output style. The default reproduces the V1.0 output byte for byte.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MarkdownStyleConfig {
+    pub heading_style: HeadingStyle,
+    /// Inserted between consecutive blocks (heading, paragraph, table).
+    /// Every block already ends its own line, so the default `"\n"` yields one
+    /// blank line between blocks and `""` packs them line after line.
+    /// The document itself always ends with a blank line, as in V1.0.
+    pub block_separator: String,
+}
+
+impl Default for MarkdownStyleConfig {
+    fn default() -> Self {
+        Self {
+            heading_style: HeadingStyle::Atx,
+            block_separator: "\n".to_string(),
+        }
+    }
+}
+
+/// Export a collection of AST sections to clean Markdown using the given style.
 ///
-/// Used by the `lib.rs` facade in `process_document`.
-pub fn export_markdown_from_sections(sections: &[Section]) -> String {
-    let mut md = String::new();
+/// Used by the `lib.rs` facade in `process_document_with_style`.
+pub fn export_markdown_with_style(sections: &[Section], style: &MarkdownStyleConfig) -> String {
+    let mut blocks: Vec<String> = Vec::new();
 
     for section in sections {
         // Section heading
-        let hashes = "#".repeat(section.level as usize);
-        md.push_str(&format!("{} {}\n\n", hashes, section.title));
-
-        for node in &section.nodes {
-            render_node(&mut md, node);
-        }
+        blocks.push(render_heading(section.level, &section.title, style));
+        blocks.extend(
+            section
+                .nodes
+                .iter()
+                .filter_map(|node| render_node(node, style)),
+        );
     }
 
+    let mut md = blocks.join(&style.block_separator);
+    if !blocks.is_empty() {
+        // Document terminator — independent of the block separator
+        md.push('\n');
+    }
     md
 }
 
-/// Render a single AST node to Markdown.
-fn render_node(md: &mut String, node: &Node) {
+/// Render a heading block in the configured style, ending with a newline.
+fn render_heading(level: u8, text: &str, style: &MarkdownStyleConfig) -> String {
+    match (style.heading_style, level) {
+        (HeadingStyle::Setext, 1) => setext_heading(text, '='),
+        (HeadingStyle::Setext, 2) => setext_heading(text, '-'),
+        _ => format!("{} {}\n", "#".repeat(level as usize), text),
+    }
+}
+
+fn setext_heading(text: &str, underline: char) -> String {
+    let width = text.chars().count().max(3);
+    let rule: String = std::iter::repeat_n(underline, width).collect();
+    format!("{}\n{}\n", text, rule)
+}
+
+/// Render a single AST node to a Markdown block ending with a newline.
+/// Returns `None` for nodes that produce no output. A table whose rows are
+/// all empty still yields an (empty) block, matching the V1.0 spacing.
+fn render_node(node: &Node, style: &MarkdownStyleConfig) -> Option<String> {
     match node {
-        Node::Heading { level, text, .. } => {
-            let hashes = "#".repeat(*level as usize);
-            md.push_str(&format!("{} {}\n\n", hashes, text));
-        }
+        Node::Heading { level, text, .. } => Some(render_heading(*level, text, style)),
         Node::Paragraph { text, .. } => {
-            if !text.trim().is_empty() {
-                md.push_str(&format!("{}\n\n", text.trim()));
-            }
+            let text = text.trim();
+            (!text.is_empty()).then(|| format!("{}\n", text))
         }
         Node::Table(table_def) => {
             if table_def.rows.is_empty() {
-                return;
+                return None;
             }
-
+            let mut md = String::new();
             for row in &table_def.rows {
                 if row.cells.is_empty() {
                     continue;
@@ -54,17 +104,14 @@ fn render_node(md: &mut String, node: &Node) {
                 // Add separator row after header
                 if row.row_type == RowType::Header {
                     md.push('|');
-                    for _ in &row.cells {
-                        md.push_str("---|");
-                    }
+                    md.push_str(&"---|".repeat(row.cells.len()));
                     md.push('\n');
                 }
             }
-            md.push('\n');
-        }
-        Node::Fragment { .. } => {
-            // Metadata markers — not rendered in Markdown output
+            Some(md)
         }
+        // Metadata markers — not rendered in Markdown output
+        Node::Fragment { .. } => None,
     }
 }
 
@@ -98,3 +145,121 @@ pub fn extract_numeric_index(section: &Section) -> Vec<NumericIndexEntry> {
 
     index
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::node::{Cell, Row, StableId, TableDefinition};
+
+    fn sample_sections() -> Vec<Section> {
+        vec![Section {
+            level: 1,
+            title: "Hợp đồng".to_string(),
+            id: StableId(1),
+            nodes: vec![
+                Node::Heading {
+                    level: 2,
+                    text: "Phụ lục".to_string(),
+                    id: StableId(2),
+                },
+                Node::Paragraph {
+                    text: "Nội dung".to_string(),
+                    id: StableId(3),
+                },
+                Node::Table(TableDefinition {
+                    id: StableId(4),
+                    rows: vec![
+                        Row {
+                            cells: vec![Cell {
+                                raw_text: "STT".to_string(),
+                                numeric_value: None,
+                            }],
+                            row_type: RowType::Header,
+                        },
+                        Row {
+                            cells: vec![Cell {
+                                raw_text: "1".to_string(),
+                                numeric_value: Some(1.0),
+                            }],
+                            row_type: RowType::Data,
+                        },
+                    ],
+                    is_broken: false,
+                    expected_columns: 1,
+                }),
+            ],
+        }]
+    }
+
+    #[test]
+    fn test_default_style_is_atx_with_blank_lines() {
+        let md = export_markdown_with_style(&sample_sections(), &MarkdownStyleConfig::default());
+        assert_eq!(
+            md,
+            "# Hợp đồng\n\n## Phụ lục\n\nNội dung\n\n| STT |\n|---|\n| 1 |\n\n"
+        );
+    }
+
+    #[test]
+    fn test_setext_headings_and_custom_separator() {
+        let style = MarkdownStyleConfig {
+            heading_style: HeadingStyle::Setext,
+            block_separator: "".to_string(),
+        };
+        let md = export_markdown_with_style(&sample_sections(), &style);
+        assert_eq!(
+            md,
+            "Hợp đồng\n========\nPhụ lục\n-------\nNội dung\n| STT |\n|---|\n| 1 |\n\n"
+        );
+    }
+
+    #[test]
+    fn test_non_newline_separator_sits_between_blocks() {
+        let style = MarkdownStyleConfig {
+            heading_style: HeadingStyle::Atx,
+            block_separator: "---\n".to_string(),
+        };
+        let md = export_markdown_with_style(&sample_sections(), &style);
+        assert_eq!(
+            md,
+            "# Hợp đồng\n---\n## Phụ lục\n---\nNội dung\n---\n| STT |\n|---|\n| 1 |\n\n"
+        );
+    }
+
+    #[test]
+    fn test_default_style_keeps_v1_spacing_for_empty_tables() {
+        let sections = vec![Section {
+            level: 1,
+            title: "Bảng".to_string(),
+            id: StableId(1),
+            nodes: vec![
+                Node::Table(TableDefinition {
+                    id: StableId(2),
+                    rows: vec![Row {
+                        cells: vec![],
+                        row_type: RowType::Data,
+                    }],
+                    is_broken: false,
+                    expected_columns: 0,
+                }),
+                Node::Table(TableDefinition {
+                    id: StableId(3),
+                    rows: vec![],
+                    is_broken: false,
+                    expected_columns: 0,
+                }),
+            ],
+        }];
+        let md = export_markdown_with_style(&sections, &MarkdownStyleConfig::default());
+        assert_eq!(md, "# Bảng\n\n\n");
+    }
+
+    #[test]
+    fn test_setext_falls_back_to_atx_below_h2() {
+        let style = MarkdownStyleConfig {
+            heading_style: HeadingStyle::Setext,
+            ..Default::default()
+        };
+        assert_eq!(render_heading(3, "Mục", &style), "### Mục\n");
+    }
+}
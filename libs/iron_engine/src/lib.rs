@@ -22,6 +22,9 @@ pub type Result<T> = std::result::Result<T, ProcessError>;
 // ─── Internal re-exports for integration tests ONLY ──────────────────────────
 pub use numeric_validator::{ValidationContext, ValidationEngine};
 
+// ─── Export style configuration ──────────────────────────────────────────────
+pub use exporter::{HeadingStyle, MarkdownStyleConfig};
+
 // ─── IPC Error Contract ───────────────────────────────────────────────────────
 /// Error codes returned by the engine.
 ///
@@ -41,6 +44,8 @@ pub enum ProcessError {
     IoError,
     #[error("EnginePanic")]
     EnginePanic,
+    #[error("OutputConflict")]
+    OutputConflict,
 }
 
 impl From<std::io::Error> for ProcessError {
@@ -101,6 +106,25 @@ pub struct IpcDiffReport {
     pub deltas: Vec<IpcDelta>,
 }
 
+/// Progress of a folder conversion, reported once per input file.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConversionProgress {
+    pub file: String,
+    pub completed: u32,
+    pub total: u32,
+    pub error: Option<ProcessError>,
+}
+
+/// Final counts of a folder conversion.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConversionSummary {
+    pub total: u32,
+    pub converted: u32,
+    pub failed: u32,
+}
+
 // ─── Public API Facade ────────────────────────────────────────────────────────
 
 /// Process a document file and return a `DocumentSummary`.
@@ -111,6 +135,16 @@ pub struct IpcDiffReport {
 /// ingestor pipeline, and returns an opaque summary containing the cached
 /// Markdown and internal index for compare operations.
 pub fn process_document(path: &std::path::Path) -> Result<DocumentSummary> {
+    process_document_with_style(path, &MarkdownStyleConfig::default())
+}
+
+/// Same as `process_document`, rendering the cached Markdown with `style`.
+///
+/// **SYNC / CPU-bound** — Tauri layer MUST call `spawn_blocking`.
+pub fn process_document_with_style(
+    path: &std::path::Path,
+    style: &MarkdownStyleConfig,
+) -> Result<DocumentSummary> {
     use ast::node::{Node, Section, StableId};
     use std::collections::hash_map::DefaultHasher;
     use std::hash::{Hash, Hasher};
@@ -191,7 +225,7 @@ pub fn process_document(path: &std::path::Path) -> Result<DocumentSummary> {
         })
         .collect();
 
-    let markdown = exporter::export_markdown_from_sections(&sections);
+    let markdown = exporter::export_markdown_with_style(&sections, style);
 
     // ── 4. Generate stable ID ─────────────────────────────────────────────────
     let id = {
@@ -269,3 +303,158 @@ pub fn compare_documents(a: &DocumentSummary, b: &DocumentSummary) -> IpcDiffRep
 pub fn get_markdown(summary: &DocumentSummary) -> &str {
     &summary.markdown
 }
+
+/// Convert every PDF in `dir` to `<name>.md` inside `out_dir`.
+///
+/// **SYNC / CPU-bound** — Tauri layer MUST call `spawn_blocking`.
+///
+/// Per-file failures never abort the batch: the file is counted as failed,
+/// an error-marker `.md` is written when possible, and `on_progress` is still
+/// called. Inputs whose output name collides with an earlier one (`a.pdf` and
+/// `a.PDF`) fail with `OutputConflict` instead of overwriting it. Only an
+/// unreadable `dir` or an uncreatable `out_dir` fail the whole call.
+pub fn convert_folder_to_markdown(
+    dir: &std::path::Path,
+    out_dir: &std::path::Path,
+    mut on_progress: impl FnMut(&ConversionProgress),
+) -> Result<ConversionSummary> {
+    let mut inputs: Vec<std::path::PathBuf> = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| {
+            p.is_file()
+                && p.extension()
+                    .and_then(|e| e.to_str())
+                    .is_some_and(|e| e.eq_ignore_ascii_case("pdf"))
+        })
+        .collect();
+    inputs.sort();
+
+    std::fs::create_dir_all(out_dir)?;
+
+    let total = inputs.len() as u32;
+    let mut summary = ConversionSummary {
+        total,
+        converted: 0,
+        failed: 0,
+    };
+    // Lowercased output names already claimed (case-insensitive filesystems)
+    let mut claimed = std::collections::HashSet::new();
+
+    for (idx, input) in inputs.iter().enumerate() {
+        let md_name = format!(
+            "{}.md",
+            input.file_stem().unwrap_or_default().to_string_lossy()
+        );
+        let md_path = out_dir.join(&md_name);
+
+        let error = if !claimed.insert(md_name.to_lowercase()) {
+            // Never touch the earlier file's output
+            Some(ProcessError::OutputConflict)
+        } else {
+            let result = process_document(input).and_then(|doc| {
+                std::fs::write(&md_path, get_markdown(&doc)).map_err(ProcessError::from)
+            });
+            if let Err(e) = &result {
+                // Best-effort marker; the failure is already counted below
+                let _ = std::fs::write(&md_path, format!("<!-- ProcessError: {} -->\n", e));
+            }
+            result.err()
+        };
+
+        if error.is_some() {
+            summary.failed += 1;
+        } else {
+            summary.converted += 1;
+        }
+
+        on_progress(&ConversionProgress {
+            file: input.to_string_lossy().to_string(),
+            completed: idx as u32 + 1,
+            total,
+            error,
+        });
+    }
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("iron_engine_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_convert_folder_writes_one_md_and_event_per_pdf() {
+        let root = scratch_dir("convert_folder");
+        let input = root.join("in");
+        let output = root.join("out");
+        std::fs::create_dir_all(&input).unwrap();
+        for name in ["a.pdf", "b.pdf", "c.PDF"] {
+            std::fs::write(input.join(name), format!("Nội dung {}", name)).unwrap();
+        }
+        std::fs::write(input.join("notes.txt"), "ignored").unwrap();
+
+        let mut events = Vec::new();
+        let summary =
+            convert_folder_to_markdown(&input, &output, |p| events.push(p.clone())).unwrap();
+
+        assert_eq!(summary.total, 3);
+        assert_eq!(summary.converted, 3);
+        assert_eq!(summary.failed, 0);
+        assert_eq!(events.len(), 3);
+        assert_eq!(
+            events.iter().map(|e| e.completed).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert!(events.iter().all(|e| e.error.is_none() && e.total == 3));
+        for name in ["a.md", "b.md", "c.md"] {
+            assert!(output.join(name).is_file(), "missing {}", name);
+        }
+        assert!(!output.join("notes.md").exists());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_convert_folder_continues_past_failures_and_collisions() {
+        let root = scratch_dir("convert_folder_failures");
+        let input = root.join("in");
+        let output = root.join("out");
+        std::fs::create_dir_all(&input).unwrap();
+        for name in ["a.pdf", "a.PDF", "b.pdf", "c.pdf"] {
+            std::fs::write(input.join(name), "text").unwrap();
+        }
+        // A directory in place of b.md makes both the write and the marker fail
+        std::fs::create_dir_all(output.join("b.md")).unwrap();
+
+        let mut events = Vec::new();
+        let summary =
+            convert_folder_to_markdown(&input, &output, |p| events.push(p.clone())).unwrap();
+
+        assert_eq!(summary.total, 4);
+        assert_eq!(summary.converted, 2); // first "a" + c
+        assert_eq!(summary.failed, 2);
+        assert_eq!(events.len(), 4);
+
+        let failed: Vec<_> = events
+            .iter()
+            .filter_map(|e| e.error.clone().map(|err| (e.file.clone(), err)))
+            .collect();
+        assert!(failed
+            .iter()
+            .any(|(_, e)| matches!(e, ProcessError::OutputConflict)));
+        assert!(failed
+            .iter()
+            .any(|(f, e)| f.ends_with("b.pdf") && matches!(e, ProcessError::IoError)));
+        assert!(output.join("c.md").is_file());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -231,6 +231,9 @@ pub struct ProjectTruth {
 
     pub project_status: ProjectStatus,
     pub status_reason: String,
+    /// Every status rule that fired, e.g. "deviation 16.00% >= 15% critical threshold"
+    #[serde(default)]
+    pub status_rules: Vec<String>,
 
     pub financials: Financials,
     pub deviation: DeviationSummary,
@@ -17,14 +17,22 @@ pub fn normalize_number(raw: f64) -> f64 {
 }
 
 /// Normalizes units to standard formats (Contract 4.2).
-/// Only exact matches are allowed.
+/// Only exact matches are allowed (case- and whitespace-insensitive).
+/// Unaccented spellings are listed explicitly; there is no accent folding, so
+/// near misses like "mẹt khòi", "mét vuong" or "bò" stay unmapped.
 pub fn normalize_unit(raw: &str) -> Option<String> {
-    match raw.trim().to_lowercase().as_str() {
-        "m2" | "mét vuông" => Some("m²".to_string()),
-        "m3" | "mét khối" => Some("m³".to_string()),
+    let lower = raw.trim().to_lowercase();
+    let key = lower.split_whitespace().collect::<Vec<&str>>().join(" ");
+
+    match key.as_str() {
+        "m2" | "m²" | "mét vuông" | "met vuong" | "m vuông" | "m vuong" => {
+            Some("m²".to_string())
+        }
+        "m3" | "m³" | "mét khối" | "met khoi" | "m khối" | "m khoi" => Some("m³".to_string()),
+        "md" | "mét dài" | "met dai" | "m dài" | "m dai" => Some("md".to_string()),
         "cái" => Some("cái".to_string()),
         "bộ" => Some("bộ".to_string()),
-        "kg" => Some("kg".to_string()),
+        "kg" | "kilogam" => Some("kg".to_string()),
         "tan" | "tấn" => Some("tấn".to_string()),
         _ => None,
     }
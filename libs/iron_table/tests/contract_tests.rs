@@ -114,6 +114,34 @@ fn test_unit_normalization() {
     assert_eq!(normalize_unit("unknown"), None);
 }
 
+#[test]
+fn test_unit_normalization_variants() {
+    for variant in ["m3", "M3", "m³", "mét khối", "m khối", "  Mét   Khối "] {
+        assert_eq!(normalize_unit(variant), Some("m³".to_string()), "variant {:?}", variant);
+    }
+    assert_eq!(normalize_unit("m vuông"), Some("m²".to_string()));
+    assert_eq!(normalize_unit("mét dài"), Some("md".to_string()));
+    assert_eq!(normalize_unit("TẤN"), Some("tấn".to_string()));
+    assert_eq!(normalize_unit("Cái"), Some("cái".to_string()));
+    assert_eq!(normalize_unit("Kilogam"), Some("kg".to_string()));
+    assert_eq!(normalize_unit("m4"), None);
+}
+
+#[test]
+fn test_unit_normalization_near_misses_stay_unmapped() {
+    // Units are matched exactly, never accent-folded
+    for near_miss in ["bò", "bỏ", "cải", "cai", "tán", "tân"] {
+        assert_eq!(normalize_unit(near_miss), None, "near miss {:?}", near_miss);
+    }
+    // Partially accented metre spellings are not in the table either
+    for near_miss in ["mẹt khòi", "mét vuong"] {
+        assert_eq!(normalize_unit(near_miss), None, "near miss {:?}", near_miss);
+    }
+    // Fully unaccented spellings are listed explicitly
+    assert_eq!(normalize_unit("met khoi"), Some("m³".to_string()));
+    assert_eq!(normalize_unit("met vuong"), Some("m²".to_string()));
+}
+
 #[test]
 fn test_number_normalization() {
     assert_eq!(normalize_number(1.2345), 1.235);
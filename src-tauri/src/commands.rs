@@ -4,10 +4,10 @@
 // All CPU-bound operations MUST use spawn_blocking (CTO requirement).
 // RULE: MutexGuard MUST be dropped before any .await boundary.
 
-use iron_engine::{DocumentSummary, IpcDiffReport, ProcessError};
+use iron_engine::{ConversionSummary, DocumentSummary, IpcDiffReport, ProcessError};
 use std::collections::HashMap;
 use std::sync::Mutex;
-use tauri::State;
+use tauri::{AppHandle, Emitter, State};
 
 // ─── Session Registry ─────────────────────────────────────────────────────────
 pub struct DocumentRegistry(pub Mutex<HashMap<String, DocumentSummary>>);
@@ -76,3 +76,25 @@ pub async fn compare_documents(
 
     Ok(report)
 }
+
+/// Convert every PDF in `dir` to `<name>.md` inside `out_dir`.
+/// Emits one `conversion-progress` event per completed file.
+#[tauri::command]
+pub async fn convert_folder_to_markdown(
+    dir: String,
+    out_dir: String,
+    app: AppHandle,
+) -> Result<ConversionSummary, ProcessError> {
+    tauri::async_runtime::spawn_blocking(move || {
+        iron_engine::convert_folder_to_markdown(
+            std::path::Path::new(&dir),
+            std::path::Path::new(&out_dir),
+            |progress| {
+                // A closed window must not abort the batch — events are best-effort
+                let _ = app.emit("conversion-progress", progress.clone());
+            },
+        )
+    })
+    .await
+    .map_err(|_| ProcessError::EnginePanic)?
+}
@@ -23,6 +23,7 @@ pub fn run() {
             commands::process_document,
             commands::export_markdown,
             commands::compare_documents,
+            commands::convert_folder_to_markdown,
         ])
         .run(tauri::generate_context!())
         .expect("Lỗi khởi động TachFileTo");
@@ -13,6 +13,7 @@ export const ERROR_MESSAGES: Record<ProcessError, string> = {
     UserCancelled: 'Đã hủy. Dữ liệu tạm thời đã được xóa.',
     IoError: 'Lỗi đọc tệp. Kiểm tra quyền truy cập thư mục.',
     EnginePanic: 'Lỗi hệ thống không xác định. Vui lòng thử lại.',
+    OutputConflict: 'Trùng tên tệp đầu ra. Tệp này đã bị bỏ qua để không ghi đè tệp khác.',
 };
 
 // ─── UI Strings ────────────────────────────────────────────────────────────────
@@ -8,7 +8,8 @@ export type ProcessError =
     | 'UnsupportedFormat'
     | 'UserCancelled'
     | 'IoError'
-    | 'EnginePanic';
+    | 'EnginePanic'
+    | 'OutputConflict';
 
 export interface DocumentSummary {
     id: string;
@@ -33,6 +34,20 @@ export interface IpcDiffReport {
     deltas: IpcDelta[];
 }
 
+// Payload of the `conversion-progress` event (convert_folder_to_markdown)
+export interface ConversionProgress {
+    file: string;
+    completed: number;
+    total: number;
+    error: { code: ProcessError } | null;
+}
+
+export interface ConversionSummary {
+    total: number;
+    converted: number;
+    failed: number;
+}
+
 // App state machine phases — CTO approved phase set
 export type AppPhase =
     | 'idle'